@@ -36,7 +36,11 @@ impl<'a> PertDot<'a> {
             write!(f, "{}{}", INDENT, g.to_index(node))?;
             write!(f, " [label=\"")?;
             node_fmt(event, &mut |d| Escaped(d).fmt(f))?;
-            writeln!(f, "\"]")?;
+            write!(f, "\"")?;
+            if event.is_synthetic() {
+                write!(f, ", style=dotted")?;
+            }
+            writeln!(f, "]")?;
         }
         // output all edges
         for edge in g.edge_references() {
@@ -52,6 +56,7 @@ impl<'a> PertDot<'a> {
             edge_fmt(edge.weight(), &mut |d| Escaped(d).fmt(f))?;
             write!(f, "\"")?;
             match edge.weight() {
+                t if t.is_synthetic() => write!(f, ", style=dotted")?,
                 t if t.is_dummy_path() => write!(f, ", style=dashed")?,
                 t if t.is_critical_path() => write!(f, ", style=bold")?,
                 _ => {}
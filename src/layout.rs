@@ -0,0 +1,132 @@
+use std::collections::{BTreeMap, HashMap};
+
+use petgraph::{graph::NodeIndex, Direction};
+
+use super::pert::PertGraph;
+
+/// Horizontal spacing between layers, in SVG user units.
+const COLUMN_WIDTH: f64 = 160.0;
+/// Vertical spacing between events within the same layer.
+const ROW_HEIGHT: f64 = 80.0;
+/// Sweeps of the barycenter heuristic to run before settling on a within-layer order.
+const BARYCENTER_SWEEPS: usize = 4;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A self-contained layered (Sugiyama-style) layout for a [`PertGraph`], so diagrams can be
+/// drawn without shelling out to Graphviz. Events are assigned to a layer by their
+/// `fastest_begin` rank (already a longest-path-from-source ordering), ordered within each
+/// layer by the classic iterated barycenter heuristic to reduce edge crossings, then given
+/// concrete x/y coordinates.
+pub struct Layout {
+    positions: HashMap<NodeIndex<u32>, Position>,
+    width: f64,
+    height: f64,
+}
+
+impl Layout {
+    pub fn compute(graph: &PertGraph) -> Layout {
+        let mut layers = group_into_layers(graph);
+        order_layers_by_barycenter(graph, &mut layers);
+        let positions = assign_coordinates(&layers);
+
+        let width = layers.len().saturating_sub(1) as f64 * COLUMN_WIDTH;
+        let height = layers
+            .values()
+            .map(|nodes| nodes.len().saturating_sub(1) as f64 * ROW_HEIGHT)
+            .fold(0.0, f64::max);
+
+        Layout {
+            positions,
+            width,
+            height,
+        }
+    }
+
+    pub fn position(&self, node: NodeIndex<u32>) -> Position {
+        self.positions[&node]
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+}
+
+/// Group events by their `fastest_begin`, which is already a longest-path rank from the
+/// source event and so gives a natural left-to-right x-layering.
+fn group_into_layers(graph: &PertGraph) -> BTreeMap<u32, Vec<NodeIndex<u32>>> {
+    let mut layers: BTreeMap<u32, Vec<NodeIndex<u32>>> = BTreeMap::new();
+    for node in graph.node_indices() {
+        let layer = graph.node_weight(node).unwrap().fastest_begin();
+        layers.entry(layer).or_default().push(node);
+    }
+    for nodes in layers.values_mut() {
+        nodes.sort_by_key(|&n| graph.node_weight(n).unwrap().label());
+    }
+    layers
+}
+
+/// Repeatedly reorder each layer by the average within-layer position of its neighbors
+/// (the barycenter heuristic), which tends to pull connected events into line and so
+/// reduces edge crossings between adjacent layers.
+fn order_layers_by_barycenter(graph: &PertGraph, layers: &mut BTreeMap<u32, Vec<NodeIndex<u32>>>) {
+    for _ in 0..BARYCENTER_SWEEPS {
+        let positions = layer_positions(layers);
+        for nodes in layers.values_mut() {
+            let mut with_barycenter: Vec<(NodeIndex<u32>, f64)> = nodes
+                .iter()
+                .map(|&n| (n, barycenter(graph, n, &positions)))
+                .collect();
+            with_barycenter
+                .sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            *nodes = with_barycenter.into_iter().map(|(n, _)| n).collect();
+        }
+    }
+}
+
+fn layer_positions(layers: &BTreeMap<u32, Vec<NodeIndex<u32>>>) -> HashMap<NodeIndex<u32>, f64> {
+    layers
+        .values()
+        .flat_map(|nodes| nodes.iter().enumerate().map(|(i, &n)| (n, i as f64)))
+        .collect()
+}
+
+fn barycenter(
+    graph: &PertGraph,
+    node: NodeIndex<u32>,
+    positions: &HashMap<NodeIndex<u32>, f64>,
+) -> f64 {
+    let neighbor_positions: Vec<f64> = graph
+        .neighbors_directed(node, Direction::Incoming)
+        .chain(graph.neighbors_directed(node, Direction::Outgoing))
+        .map(|neighbor| positions[&neighbor])
+        .collect();
+
+    if neighbor_positions.is_empty() {
+        positions[&node]
+    } else {
+        neighbor_positions.iter().sum::<f64>() / neighbor_positions.len() as f64
+    }
+}
+
+fn assign_coordinates(
+    layers: &BTreeMap<u32, Vec<NodeIndex<u32>>>,
+) -> HashMap<NodeIndex<u32>, Position> {
+    let mut positions = HashMap::new();
+    for (column, nodes) in layers.values().enumerate() {
+        let x = column as f64 * COLUMN_WIDTH;
+        for (row, &node) in nodes.iter().enumerate() {
+            let y = row as f64 * ROW_HEIGHT;
+            positions.insert(node, Position { x, y });
+        }
+    }
+    positions
+}
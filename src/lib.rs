@@ -0,0 +1,4 @@
+pub mod dot;
+pub mod layout;
+pub mod pert;
+pub mod svg;
@@ -1,22 +1,30 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 
-mod dot;
-mod pert;
-
-use dot::PertDot;
-use pert::DataLoader;
+use pertrs::dot::PertDot;
+use pertrs::pert::DataLoader;
+use pertrs::svg::PertSvg;
 
 fn main() -> Result<()> {
-    //  CSV file example (start, end, task duration, task name)
-    //   1, 2, 1, task1
-    //   2, 3, 3, task2
-    //   1, 3, 5, task3
-    //   1, 4, 10, task4
-    //   3, 4, 2, task5
+    //  CSV file example
+    //  (start, end, optimistic, most likely, pessimistic, crash duration, cost slope, task name)
+    //   1, 2, 1, 1, 3, 1, 50.0, task1
+    //   2, 3, 2, 3, 5, 2, 75.0, task2
+    //   1, 3, 4, 5, 8, 3, 40.0, task3
+    //   1, 4, 8, 10, 14, 6, 30.0, task4
+    //   3, 4, 1, 2, 4, 1, 60.0, task5
+    //
+    //  Usage: pertrs [dot|svg] < data.csv
+    //  Defaults to "dot" when no format is given.
+
+    let format = std::env::args().nth(1).unwrap_or_else(|| "dot".to_string());
 
     let data_loader = DataLoader::from_stdin()?;
     let pert = data_loader.to_graph()?;
 
-    println!("{}", PertDot::new(&pert.0));
+    match format.as_str() {
+        "dot" => println!("{}", PertDot::new(&pert.0)),
+        "svg" => println!("{}", PertSvg::new(&pert.0)),
+        other => bail!("unknown output format \"{other}\" (expected \"dot\" or \"svg\")"),
+    }
     Ok(())
 }
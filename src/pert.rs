@@ -1,31 +1,68 @@
 use anyhow::{bail, Result};
-use itertools::Itertools;
 use petgraph::{
+    visit::EdgeRef,
     Direction,
     {
-        algo::all_simple_paths,
+        algo::toposort,
         graph::{EdgeIndex, Graph, NodeIndex},
     },
 };
+use rand::Rng;
+use rand_distr::{Beta, Distribution};
+use rayon::prelude::*;
 use serde::Deserialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::{fmt, io};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Task {
     name: String,
+    optimistic: f64,
+    most_likely: f64,
+    pessimistic: f64,
+    variance: f64,
     duration: u32,
+    crash_duration: u32,
+    cost_slope: f64,
     total_float: u32,
     free_float: u32,
+    synthetic: bool,
 }
 
 impl Task {
-    fn new(name: &str, duration: u32) -> Task {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        name: &str,
+        optimistic: f64,
+        most_likely: f64,
+        pessimistic: f64,
+        crash_duration: u32,
+        cost_slope: f64,
+    ) -> Task {
+        let expected = (optimistic + 4.0 * most_likely + pessimistic) / 6.0;
+        let variance = ((pessimistic - optimistic) / 6.0).powi(2);
         Task {
             name: String::from(name),
+            optimistic,
+            most_likely,
+            pessimistic,
+            variance,
+            duration: expected.round() as u32,
+            crash_duration,
+            cost_slope,
             total_float: 0,
             free_float: 0,
-            duration,
+            synthetic: false,
+        }
+    }
+
+    /// A zero-duration connector edge inserted by [`normalize_sources_and_sinks`] to join a
+    /// synthetic super-source/super-sink to the project's real entry/exit events. It can
+    /// never be crashed and carries no cost.
+    fn dummy(name: &str) -> Task {
+        Task {
+            synthetic: true,
+            ..Task::new(name, 0.0, 0.0, 0.0, 0, 0.0)
         }
     }
 
@@ -36,6 +73,38 @@ impl Task {
     pub fn is_dummy_path(&self) -> bool {
         self.duration == 0
     }
+
+    /// Whether this task is a synthetic connector inserted to give a multi-source or
+    /// multi-sink project a single CPM origin/terminus, rather than a real dependency.
+    pub fn is_synthetic(&self) -> bool {
+        self.synthetic
+    }
+
+    /// Expected duration `(o + 4m + p) / 6`, the beta-approximation mean used for CPM.
+    pub fn expected_duration(&self) -> f64 {
+        (self.optimistic + 4.0 * self.most_likely + self.pessimistic) / 6.0
+    }
+
+    /// Beta-approximation variance `((p - o) / 6)^2`.
+    pub fn variance(&self) -> f64 {
+        self.variance
+    }
+
+    fn can_crash(&self) -> bool {
+        self.duration > self.crash_duration
+    }
+
+    /// Draw a single duration from the PERT-Beta distribution implied by `(o, m, p)`.
+    fn sample_duration(&self, rng: &mut impl Rng) -> f64 {
+        let range = self.pessimistic - self.optimistic;
+        if range <= 0.0 {
+            return self.optimistic;
+        }
+        let alpha = 1.0 + 4.0 * (self.most_likely - self.optimistic) / range;
+        let beta = 1.0 + 4.0 * (self.pessimistic - self.most_likely) / range;
+        let sample = Beta::new(alpha, beta).unwrap().sample(rng);
+        self.optimistic + sample * range
+    }
 }
 
 impl fmt::Display for Task {
@@ -48,11 +117,12 @@ impl fmt::Display for Task {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Event {
     label: u32,
     fastest_begin: u32,
     latest_finish: u32,
+    synthetic: bool,
 }
 
 impl Event {
@@ -61,8 +131,34 @@ impl Event {
             label: *label,
             fastest_begin: 0,
             latest_finish: 0,
+            synthetic: false,
+        }
+    }
+
+    /// A synthetic super-source/super-sink event inserted by
+    /// [`normalize_sources_and_sinks`] so the CPM passes have a single origin/terminus.
+    fn synthetic(label: u32) -> Event {
+        Event {
+            label,
+            fastest_begin: 0,
+            latest_finish: 0,
+            synthetic: true,
         }
     }
+
+    /// Whether this event is a synthetic super-source/super-sink rather than a real one
+    /// from the input data.
+    pub fn is_synthetic(&self) -> bool {
+        self.synthetic
+    }
+
+    pub fn label(&self) -> u32 {
+        self.label
+    }
+
+    pub fn fastest_begin(&self) -> u32 {
+        self.fastest_begin
+    }
 }
 
 impl fmt::Display for Event {
@@ -76,8 +172,8 @@ impl fmt::Display for Event {
 }
 pub type PertGraph = Graph<Event, Task>;
 
-fn start_node(graph: &PertGraph) -> Result<NodeIndex<u32>> {
-    let start_node: Vec<NodeIndex<u32>> = graph
+fn zero_in_degree_nodes(graph: &PertGraph) -> Vec<NodeIndex<u32>> {
+    graph
         .node_indices()
         .filter(|n| {
             graph
@@ -85,16 +181,11 @@ fn start_node(graph: &PertGraph) -> Result<NodeIndex<u32>> {
                 .next()
                 .is_none()
         })
-        .collect();
-    match &start_node[..] {
-        [] => bail!("Start node is not exist"),
-        [node_index] => Ok(*node_index),
-        _ => bail!("Start node is duplicated"),
-    }
+        .collect()
 }
 
-fn end_node(graph: &PertGraph) -> Result<NodeIndex<u32>> {
-    let end_node: Vec<NodeIndex<u32>> = graph
+fn zero_out_degree_nodes(graph: &PertGraph) -> Vec<NodeIndex<u32>> {
+    graph
         .node_indices()
         .filter(|n| {
             graph
@@ -102,41 +193,87 @@ fn end_node(graph: &PertGraph) -> Result<NodeIndex<u32>> {
                 .next()
                 .is_none()
         })
-        .collect();
-    match &end_node[..] {
+        .collect()
+}
+
+fn start_node(graph: &PertGraph) -> Result<NodeIndex<u32>> {
+    match &zero_in_degree_nodes(graph)[..] {
+        [] => bail!("Start node is not exist"),
+        [node_index] => Ok(*node_index),
+        _ => bail!("Start node is duplicated"),
+    }
+}
+
+fn end_node(graph: &PertGraph) -> Result<NodeIndex<u32>> {
+    match &zero_out_degree_nodes(graph)[..] {
         [] => bail!("End node is not exist"),
         [node_index] => Ok(*node_index),
         _ => bail!("End node is duplicated"),
     }
 }
 
-fn compute_fastest_begin(graph: &PertGraph, from: &NodeIndex<u32>, to: &NodeIndex<u32>) -> u32 {
-    all_simple_paths(graph, from.to_owned(), to.to_owned(), 0, None)
-        .map(|path: Vec<NodeIndex<u32>>| {
-            path.iter().tuple_windows().fold(0u32, |begin, (n1, n2)| {
-                let edge = graph.find_edge(n1.to_owned(), n2.to_owned()).unwrap();
-                let task = graph.edge_weight(edge).unwrap();
-                begin + task.duration
-            })
-        })
-        .max()
-        .unwrap_or(0)
-}
-
-fn compute_latest_finish(graph: &PertGraph, from: &NodeIndex<u32>, to: &NodeIndex<u32>) -> u32 {
-    let total_time = graph.node_weight(to.to_owned()).unwrap().fastest_begin;
-    all_simple_paths(graph, from.to_owned(), to.to_owned(), 0, None)
-        .map(|path: Vec<NodeIndex<u32>>| {
-            path.iter()
-                .tuple_windows()
-                .fold(total_time, |finish, (n1, n2)| {
-                    let edge = graph.find_edge(n1.to_owned(), n2.to_owned()).unwrap();
-                    let task = graph.edge_weight(edge).unwrap();
-                    finish - task.duration
-                })
-        })
-        .min()
-        .unwrap_or(total_time)
+/// When a project has several independent kickoff tasks or deliverables, `start_node`/
+/// `end_node` would otherwise reject it for having more than one zero-in/out-degree event.
+/// Join every such event to a synthetic super-source/super-sink with a zero-duration dummy
+/// task so the CPM passes still have a single origin and terminus.
+fn normalize_sources_and_sinks(graph: &mut PertGraph) {
+    let mut next_label = graph.node_weights().map(|e| e.label).max().unwrap_or(0) + 1;
+
+    let sources = zero_in_degree_nodes(graph);
+    if sources.len() > 1 {
+        let super_source = graph.add_node(Event::synthetic(next_label));
+        next_label += 1;
+        for source in sources {
+            graph.add_edge(super_source, source, Task::dummy("synthetic-source"));
+        }
+    }
+
+    let sinks = zero_out_degree_nodes(graph);
+    if sinks.len() > 1 {
+        let super_sink = graph.add_node(Event::synthetic(next_label));
+        for sink in sinks {
+            graph.add_edge(sink, super_sink, Task::dummy("synthetic-sink"));
+        }
+    }
+}
+
+fn topological_order(graph: &PertGraph) -> Result<Vec<NodeIndex<u32>>> {
+    toposort(graph, None).map_err(|_| anyhow::anyhow!("Graph contains a cycle"))
+}
+
+fn compute_fastest_begins(
+    graph: &PertGraph,
+    order: &[NodeIndex<u32>],
+) -> HashMap<NodeIndex<u32>, u32> {
+    let mut fastest_begin: HashMap<NodeIndex<u32>, u32> = HashMap::new();
+    for node_index in order.iter() {
+        let begin = graph
+            .edges_directed(*node_index, Direction::Incoming)
+            .map(|edge| fastest_begin[&edge.source()] + edge.weight().duration)
+            .max()
+            .unwrap_or(0);
+        fastest_begin.insert(*node_index, begin);
+    }
+    fastest_begin
+}
+
+fn compute_latest_finishes(
+    graph: &PertGraph,
+    order: &[NodeIndex<u32>],
+    fastest_begins: &HashMap<NodeIndex<u32>, u32>,
+    end_node_index: &NodeIndex<u32>,
+) -> HashMap<NodeIndex<u32>, u32> {
+    let total_time = fastest_begins[end_node_index];
+    let mut latest_finish: HashMap<NodeIndex<u32>, u32> = HashMap::new();
+    for node_index in order.iter().rev() {
+        let finish = graph
+            .edges_directed(*node_index, Direction::Outgoing)
+            .map(|edge| latest_finish[&edge.target()] - edge.weight().duration)
+            .min()
+            .unwrap_or(total_time);
+        latest_finish.insert(*node_index, finish);
+    }
+    latest_finish
 }
 
 struct Floats {
@@ -158,28 +295,526 @@ fn compute_floats(graph: &PertGraph, edge_index: &EdgeIndex<u32>) -> Floats {
 
 pub struct Pert(pub PertGraph);
 
+/// Run the forward/backward CPM passes and refresh every task's floats in place.
+fn compute_cpm(graph: &mut PertGraph) -> Result<()> {
+    start_node(graph)?;
+    let end_node_index = end_node(graph)?;
+    let order = topological_order(graph)?;
+
+    let fastest_begins = compute_fastest_begins(graph, &order);
+    for (node_index, fastest_begin) in fastest_begins.iter() {
+        graph.node_weight_mut(*node_index).unwrap().fastest_begin = *fastest_begin;
+    }
+
+    let latest_finishes =
+        compute_latest_finishes(graph, &order, &fastest_begins, &end_node_index);
+    for (node_index, latest_finish) in latest_finishes.iter() {
+        graph.node_weight_mut(*node_index).unwrap().latest_finish = *latest_finish;
+    }
+
+    for edge_index in graph.edge_indices() {
+        let floats = compute_floats(graph, &edge_index);
+        let edge_task = graph.edge_weight_mut(edge_index).unwrap();
+        edge_task.total_float = floats.total_float;
+        edge_task.free_float = floats.free_float;
+    }
+
+    Ok(())
+}
+
 impl Pert {
     fn new(mut graph: PertGraph) -> Result<Pert> {
-        let start_node_index = start_node(&graph)?;
-        for node_index in graph.node_indices() {
-            let fastest_begin = compute_fastest_begin(&graph, &start_node_index, &node_index);
-            graph.node_weight_mut(node_index).unwrap().fastest_begin = fastest_begin;
+        normalize_sources_and_sinks(&mut graph);
+        compute_cpm(&mut graph)?;
+        Ok(Pert(graph))
+    }
+
+    /// Run `n` Monte Carlo trials of the project, sampling each task's duration from its
+    /// PERT-Beta distribution, and summarize the resulting project completion times.
+    ///
+    /// `percentiles` (e.g. `&[50.0, 80.0, 95.0]`) selects which completion-time percentiles
+    /// to report alongside the mean/std-dev. The summary also carries each task's
+    /// criticality index: the fraction of trials in which it lay on that trial's critical
+    /// path.
+    ///
+    /// Trials run in parallel with rayon, since they are independent of one another.
+    pub fn simulate(&self, n: usize, percentiles: &[f64]) -> Result<SimulationSummary> {
+        if n == 0 {
+            bail!("simulate requires at least one trial");
         }
+        if let Some(&p) = percentiles.iter().find(|&&p| !(0.0..=100.0).contains(&p)) {
+            bail!("percentile {} is out of range 0.0..=100.0", p);
+        }
+
+        let graph = &self.0;
+        let end_node_index = end_node(graph)?;
+        let order = topological_order(graph)?;
 
-        let end_node_index = end_node(&graph)?;
-        for node_index in graph.node_indices() {
-            let latest_finish = compute_latest_finish(&graph, &node_index, &end_node_index);
-            graph.node_weight_mut(node_index).unwrap().latest_finish = latest_finish;
+        let trials: Vec<TrialResult> = (0..n)
+            .into_par_iter()
+            .map_init(rand::thread_rng, |rng, _| {
+                simulate_once(graph, &order, &end_node_index, rng)
+            })
+            .collect();
+
+        let completion_times: Vec<f64> = trials.iter().map(|trial| trial.completion).collect();
+
+        let mut critical_counts: HashMap<EdgeIndex<u32>, usize> = HashMap::new();
+        for trial in &trials {
+            for &edge_index in &trial.critical_edges {
+                *critical_counts.entry(edge_index).or_insert(0) += 1;
+            }
         }
+        let criticality: Vec<TaskCriticality> = graph
+            .edge_indices()
+            .map(|edge_index| TaskCriticality {
+                name: graph.edge_weight(edge_index).unwrap().name.clone(),
+                index: *critical_counts.get(&edge_index).unwrap_or(&0) as f64 / n as f64,
+            })
+            .collect();
+
+        Ok(SimulationSummary::from_trials(
+            &completion_times,
+            percentiles,
+            criticality,
+        ))
+    }
+
+    /// Enumerate every critical path from source to sink: a maximal chain of tasks whose
+    /// `total_float == 0`. A project can have several co-critical paths, so this returns all
+    /// of them, each carrying its own total duration so callers can report schedule drivers,
+    /// feed the Monte Carlo criticality index, or drive the crashing solver programmatically.
+    pub fn critical_paths(&self) -> Result<Vec<CriticalPath>> {
+        let graph = &self.0;
+        let source = start_node(graph)?;
+        let sink = end_node(graph)?;
+
+        let mut paths = Vec::new();
+        let mut events = vec![graph.node_weight(source).unwrap().label];
+        let mut tasks = Vec::new();
+        collect_critical_paths(graph, source, sink, 0, &mut events, &mut tasks, &mut paths);
+        Ok(paths)
+    }
+
+    /// Shorten the project to `target` days at minimum marginal cost, via Fulkerson's
+    /// project-crashing algorithm: repeatedly take the min-cost cut of the critical
+    /// sub-network (arc capacities are each task's per-day crashing cost) and crash every
+    /// task in that cut by one day, until the target is reached or no critical task has any
+    /// crashable slack left.
+    pub fn crash(&self, target: u32) -> Result<CrashResult> {
+        let mut graph = self.0.clone();
+        let source = start_node(&graph)?;
+        let sink = end_node(&graph)?;
+        let mut days_crashed: HashMap<EdgeIndex<u32>, u32> = HashMap::new();
+        let mut total_cost = 0.0;
 
-        for edge_index in graph.edge_indices() {
-            let floats = compute_floats(&graph, &edge_index);
-            let edge_task = graph.edge_weight_mut(edge_index).unwrap();
-            edge_task.total_float = floats.total_float;
-            edge_task.free_float = floats.free_float;
+        loop {
+            compute_cpm(&mut graph)?;
+            if graph.node_weight(sink).unwrap().fastest_begin <= target {
+                break;
+            }
+
+            let critical: Vec<EdgeIndex<u32>> = graph
+                .edge_indices()
+                .filter(|e| graph.edge_weight(*e).unwrap().is_critical_path())
+                .collect();
+            let cut = min_cost_cut(&graph, &critical, source, sink);
+            if cut.is_empty() || cut.iter().all(|e| !graph.edge_weight(*e).unwrap().can_crash()) {
+                bail!(
+                    "cannot crash project to {} days: no crashable critical activities remain",
+                    target
+                );
+            }
+
+            for edge_index in cut {
+                let task = graph.edge_weight_mut(edge_index).unwrap();
+                if !task.can_crash() {
+                    continue;
+                }
+                task.duration -= 1;
+                total_cost += task.cost_slope;
+                *days_crashed.entry(edge_index).or_insert(0) += 1;
+            }
         }
 
-        Ok(Pert(graph))
+        let tasks = days_crashed
+            .into_iter()
+            .map(|(edge_index, days)| {
+                let task = graph.edge_weight(edge_index).unwrap();
+                TaskCrash {
+                    name: task.name.clone(),
+                    days_crashed: days,
+                    cost: days as f64 * task.cost_slope,
+                }
+            })
+            .collect();
+
+        Ok(CrashResult {
+            target,
+            achieved_duration: graph.node_weight(sink).unwrap().fastest_begin,
+            total_cost,
+            tasks,
+        })
+    }
+}
+
+/// Capacity scale factor so cost slopes (which may be fractional) survive the integer
+/// max-flow computation below without losing precision.
+const COST_SCALE: f64 = 1_000.0;
+
+/// Arcs on a critical path that can no longer be crashed act as if they had infinite
+/// capacity: the min cut must never "cut" through one of them, since doing so would not
+/// actually shorten the project.
+const INFINITE_CAPACITY: i64 = i64::MAX / 4;
+
+fn crash_capacity(task: &Task) -> i64 {
+    if task.can_crash() {
+        (task.cost_slope * COST_SCALE).round() as i64
+    } else {
+        INFINITE_CAPACITY
+    }
+}
+
+/// Per-edge bookkeeping for [`min_cost_cut`]'s max-flow network, keyed by `EdgeIndex` rather
+/// than `(NodeIndex, NodeIndex)` so parallel critical edges between the same pair of events
+/// (legal in this crate's multigraph CSV format) each get their own capacity instead of
+/// overwriting one another.
+struct FlowNetwork {
+    capacity: HashMap<EdgeIndex<u32>, i64>,
+    flow: HashMap<EdgeIndex<u32>, i64>,
+    endpoints: HashMap<EdgeIndex<u32>, (NodeIndex<u32>, NodeIndex<u32>)>,
+    out_edges: HashMap<NodeIndex<u32>, Vec<EdgeIndex<u32>>>,
+    in_edges: HashMap<NodeIndex<u32>, Vec<EdgeIndex<u32>>>,
+}
+
+/// Find the minimum-cost set of critical-path arcs whose removal disconnects `source` from
+/// `sink` in the critical sub-network, via Edmonds-Karp max-flow/min-cut duality.
+fn min_cost_cut(
+    graph: &PertGraph,
+    critical_edges: &[EdgeIndex<u32>],
+    source: NodeIndex<u32>,
+    sink: NodeIndex<u32>,
+) -> Vec<EdgeIndex<u32>> {
+    let mut network = FlowNetwork {
+        capacity: HashMap::new(),
+        flow: HashMap::new(),
+        endpoints: HashMap::new(),
+        out_edges: HashMap::new(),
+        in_edges: HashMap::new(),
+    };
+    for &edge_index in critical_edges {
+        let (from, to) = graph.edge_endpoints(edge_index).unwrap();
+        let task = graph.edge_weight(edge_index).unwrap();
+        network.capacity.insert(edge_index, crash_capacity(task));
+        network.flow.insert(edge_index, 0);
+        network.endpoints.insert(edge_index, (from, to));
+        network.out_edges.entry(from).or_default().push(edge_index);
+        network.in_edges.entry(to).or_default().push(edge_index);
+    }
+
+    while let Some(path) = find_augmenting_path(&network, source, sink) {
+        let bottleneck = path
+            .iter()
+            .map(|&(edge_index, forward)| {
+                if forward {
+                    network.capacity[&edge_index] - network.flow[&edge_index]
+                } else {
+                    network.flow[&edge_index]
+                }
+            })
+            .min()
+            .unwrap();
+        for (edge_index, forward) in path {
+            let delta = if forward { bottleneck } else { -bottleneck };
+            *network.flow.get_mut(&edge_index).unwrap() += delta;
+        }
+    }
+
+    let reachable = reachable_from(&network, source);
+    network
+        .endpoints
+        .into_iter()
+        .filter(|(_, (from, to))| reachable.contains(from) && !reachable.contains(to))
+        .map(|(edge_index, _)| edge_index)
+        .collect()
+}
+
+/// `(edge, true)` means traverse the edge forward along its residual capacity; `(edge,
+/// false)` means traverse it backward by cancelling flow already pushed through it.
+fn find_augmenting_path(
+    network: &FlowNetwork,
+    source: NodeIndex<u32>,
+    sink: NodeIndex<u32>,
+) -> Option<Vec<(EdgeIndex<u32>, bool)>> {
+    let empty = Vec::new();
+    let mut parent: HashMap<NodeIndex<u32>, (NodeIndex<u32>, EdgeIndex<u32>, bool)> =
+        HashMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        if node == sink {
+            break;
+        }
+        for &edge_index in network.out_edges.get(&node).unwrap_or(&empty) {
+            let (from, to) = network.endpoints[&edge_index];
+            if network.capacity[&edge_index] - network.flow[&edge_index] > 0
+                && to != source
+                && !parent.contains_key(&to)
+            {
+                parent.insert(to, (from, edge_index, true));
+                queue.push_back(to);
+            }
+        }
+        for &edge_index in network.in_edges.get(&node).unwrap_or(&empty) {
+            let (from, to) = network.endpoints[&edge_index];
+            if network.flow[&edge_index] > 0 && from != source && !parent.contains_key(&from) {
+                parent.insert(from, (to, edge_index, false));
+                queue.push_back(from);
+            }
+        }
+    }
+
+    if !parent.contains_key(&sink) {
+        return None;
+    }
+
+    let mut path = Vec::new();
+    let mut node = sink;
+    while node != source {
+        let (prev, edge_index, forward) = parent[&node];
+        path.push((edge_index, forward));
+        node = prev;
+    }
+    path.reverse();
+    Some(path)
+}
+
+fn reachable_from(network: &FlowNetwork, source: NodeIndex<u32>) -> HashSet<NodeIndex<u32>> {
+    let empty = Vec::new();
+    let mut reachable = HashSet::new();
+    reachable.insert(source);
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        for &edge_index in network.out_edges.get(&node).unwrap_or(&empty) {
+            let (_, to) = network.endpoints[&edge_index];
+            if network.capacity[&edge_index] - network.flow[&edge_index] > 0
+                && !reachable.contains(&to)
+            {
+                reachable.insert(to);
+                queue.push_back(to);
+            }
+        }
+        for &edge_index in network.in_edges.get(&node).unwrap_or(&empty) {
+            let (from, _) = network.endpoints[&edge_index];
+            if network.flow[&edge_index] > 0 && !reachable.contains(&from) {
+                reachable.insert(from);
+                queue.push_back(from);
+            }
+        }
+    }
+    reachable
+}
+
+/// Extend every critical (`total_float == 0`) chain reachable from `node`, recording a
+/// [`CriticalPath`] each time one reaches `sink`. A node can have several outgoing critical
+/// edges, so this can discover more than one co-critical path.
+#[allow(clippy::too_many_arguments)]
+fn collect_critical_paths(
+    graph: &PertGraph,
+    node: NodeIndex<u32>,
+    sink: NodeIndex<u32>,
+    duration: u32,
+    events: &mut Vec<u32>,
+    tasks: &mut Vec<String>,
+    paths: &mut Vec<CriticalPath>,
+) {
+    if node == sink {
+        paths.push(CriticalPath {
+            events: events.clone(),
+            tasks: tasks.clone(),
+            duration,
+        });
+        return;
+    }
+
+    for edge in graph.edges_directed(node, Direction::Outgoing) {
+        let task = edge.weight();
+        if !task.is_critical_path() {
+            continue;
+        }
+        events.push(graph.node_weight(edge.target()).unwrap().label);
+        tasks.push(task.name.clone());
+        collect_critical_paths(
+            graph,
+            edge.target(),
+            sink,
+            duration + task.duration,
+            events,
+            tasks,
+            paths,
+        );
+        events.pop();
+        tasks.pop();
+    }
+}
+
+/// A single maximal chain of critical activities from source to sink, as returned by
+/// [`Pert::critical_paths`]. `events` holds the event labels visited in order, one longer
+/// than `tasks`, since each task connects consecutive events.
+#[derive(Debug)]
+pub struct CriticalPath {
+    pub events: Vec<u32>,
+    pub tasks: Vec<String>,
+    pub duration: u32,
+}
+
+/// Result of [`Pert::crash`]: how many days each task was expedited, at what cost.
+#[derive(Debug)]
+pub struct CrashResult {
+    pub target: u32,
+    pub achieved_duration: u32,
+    pub total_cost: f64,
+    pub tasks: Vec<TaskCrash>,
+}
+
+#[derive(Debug)]
+pub struct TaskCrash {
+    pub name: String,
+    pub days_crashed: u32,
+    pub cost: f64,
+}
+
+/// Outcome of a single Monte Carlo trial: the sampled project completion time, plus the
+/// edges that turned out to lie on that trial's own critical path (`total_float == 0` under
+/// the trial's sampled durations, not the deterministic CPM durations).
+struct TrialResult {
+    completion: f64,
+    critical_edges: Vec<EdgeIndex<u32>>,
+}
+
+/// Run a single Monte Carlo trial: sample every task's duration, then run the same
+/// forward/backward CPM passes as `compute_cpm` over the sampled f64 durations instead of
+/// the fixed expected `Task::duration`, to find both the makespan and this trial's own
+/// critical edges.
+fn simulate_once(
+    graph: &PertGraph,
+    order: &[NodeIndex<u32>],
+    end_node_index: &NodeIndex<u32>,
+    rng: &mut impl Rng,
+) -> TrialResult {
+    let durations: HashMap<EdgeIndex<u32>, f64> = graph
+        .edge_indices()
+        .map(|edge_index| (edge_index, graph.edge_weight(edge_index).unwrap().sample_duration(rng)))
+        .collect();
+
+    let mut fastest_begin: HashMap<NodeIndex<u32>, f64> = HashMap::new();
+    for node_index in order.iter() {
+        let begin = graph
+            .edges_directed(*node_index, Direction::Incoming)
+            .map(|edge| fastest_begin[&edge.source()] + durations[&edge.id()])
+            .fold(0.0_f64, f64::max);
+        fastest_begin.insert(*node_index, begin);
+    }
+    let completion = fastest_begin[end_node_index];
+
+    let mut latest_finish: HashMap<NodeIndex<u32>, f64> = HashMap::new();
+    for node_index in order.iter().rev() {
+        let finish = graph
+            .edges_directed(*node_index, Direction::Outgoing)
+            .map(|edge| latest_finish[&edge.target()] - durations[&edge.id()])
+            .fold(f64::INFINITY, f64::min);
+        latest_finish.insert(*node_index, if finish.is_finite() { finish } else { completion });
+    }
+
+    const EPSILON: f64 = 1e-6;
+    let critical_edges = graph
+        .edge_indices()
+        .filter(|&edge_index| {
+            let (from, to) = graph.edge_endpoints(edge_index).unwrap();
+            let float = latest_finish[&to] - (fastest_begin[&from] + durations[&edge_index]);
+            float.abs() < EPSILON
+        })
+        .collect();
+
+    TrialResult {
+        completion,
+        critical_edges,
+    }
+}
+
+/// A task's criticality index, as reported by [`Pert::simulate`]: the fraction of Monte
+/// Carlo trials in which the task lay on that trial's own critical path.
+#[derive(Debug)]
+pub struct TaskCriticality {
+    pub name: String,
+    pub index: f64,
+}
+
+/// Summary statistics over the project completion times of a Monte Carlo simulation.
+#[derive(Debug)]
+pub struct SimulationSummary {
+    pub trials: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    /// `(percentile, completion time)` pairs for each percentile requested of `simulate`.
+    pub percentiles: Vec<(f64, f64)>,
+    pub criticality: Vec<TaskCriticality>,
+}
+
+impl SimulationSummary {
+    fn from_trials(
+        completion_times: &[f64],
+        percentiles: &[f64],
+        criticality: Vec<TaskCriticality>,
+    ) -> SimulationSummary {
+        let trials = completion_times.len();
+        let mean = completion_times.iter().sum::<f64>() / trials as f64;
+        let variance = completion_times
+            .iter()
+            .map(|t| (t - mean).powi(2))
+            .sum::<f64>()
+            / trials as f64;
+        let min = completion_times.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = completion_times
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let mut sorted = completion_times.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let percentiles = percentiles
+            .iter()
+            .map(|&p| (p, percentile(&sorted, p)))
+            .collect();
+
+        SimulationSummary {
+            trials,
+            mean,
+            std_dev: variance.sqrt(),
+            min,
+            max,
+            percentiles,
+            criticality,
+        }
+    }
+}
+
+/// Linear-interpolated percentile `p` (0..=100) of an already-sorted sample.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let rank = (p / 100.0) * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        let frac = rank - lower as f64;
+        sorted[lower] * (1.0 - frac) + sorted[upper] * frac
     }
 }
 
@@ -187,7 +822,11 @@ impl Pert {
 struct Row {
     from: u32,
     to: u32,
-    weight: u32,
+    optimistic: f64,
+    most_likely: f64,
+    pessimistic: f64,
+    crash_duration: u32,
+    cost_slope: f64,
     name: String,
 }
 
@@ -237,8 +876,242 @@ impl DataLoader {
         for row in self.rows.iter() {
             let from_node = node_index_map.get(&row.from).unwrap();
             let to_node = node_index_map.get(&row.to).unwrap();
-            graph.add_edge(*from_node, *to_node, Task::new(&row.name, row.weight));
+            graph.add_edge(
+                *from_node,
+                *to_node,
+                Task::new(
+                    &row.name,
+                    row.optimistic,
+                    row.most_likely,
+                    row.pessimistic,
+                    row.crash_duration,
+                    row.cost_slope,
+                ),
+            );
         }
         Ok(Pert::new(graph)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two paths from 1 to 3: a single task3 (duration 4) and the chain task1 -> task2
+    /// (duration 1 + 2 = 3). All estimates are deterministic (o == m == p), so task3 is
+    /// always the sole critical path.
+    fn sample_pert() -> Pert {
+        let csv = "\
+1,2,1,1,1,1,50.0,task1
+2,3,2,2,2,1,25.0,task2
+1,3,4,4,4,1,10.0,task3
+";
+        DataLoader::from_bytes(csv.as_bytes())
+            .unwrap()
+            .to_graph()
+            .unwrap()
+    }
+
+    #[test]
+    fn compute_cpm_matches_known_values_on_a_diamond_network() {
+        // Two paths from 1 to 4: 1->2->4 (duration 3+4=7) and 1->3->4 (duration 2+6=8), so
+        // the second path is critical and the first carries one day of float.
+        let csv = "\
+1,2,3,3,3,1,1.0,task1
+2,4,4,4,4,1,1.0,task2
+1,3,2,2,2,1,1.0,task3
+3,4,6,6,6,1,1.0,task4
+";
+        let pert = DataLoader::from_bytes(csv.as_bytes())
+            .unwrap()
+            .to_graph()
+            .unwrap();
+        let graph = &pert.0;
+
+        let node = |label: u32| {
+            graph
+                .node_indices()
+                .find(|&n| graph.node_weight(n).unwrap().label == label)
+                .unwrap()
+        };
+        let task = |from: u32, to: u32| {
+            graph
+                .edge_weight(graph.find_edge(node(from), node(to)).unwrap())
+                .unwrap()
+        };
+
+        assert_eq!(graph.node_weight(node(1)).unwrap().fastest_begin, 0);
+        assert_eq!(graph.node_weight(node(2)).unwrap().fastest_begin, 3);
+        assert_eq!(graph.node_weight(node(3)).unwrap().fastest_begin, 2);
+        assert_eq!(graph.node_weight(node(4)).unwrap().fastest_begin, 8);
+
+        assert_eq!(graph.node_weight(node(1)).unwrap().latest_finish, 0);
+        assert_eq!(graph.node_weight(node(2)).unwrap().latest_finish, 4);
+        assert_eq!(graph.node_weight(node(3)).unwrap().latest_finish, 2);
+        assert_eq!(graph.node_weight(node(4)).unwrap().latest_finish, 8);
+
+        assert_eq!(task(1, 2).total_float, 1);
+        assert_eq!(task(1, 2).free_float, 0);
+        assert_eq!(task(2, 4).total_float, 1);
+        assert_eq!(task(2, 4).free_float, 1);
+        assert_eq!(task(1, 3).total_float, 0);
+        assert_eq!(task(1, 3).free_float, 0);
+        assert_eq!(task(3, 4).total_float, 0);
+        assert_eq!(task(3, 4).free_float, 0);
+    }
+
+    #[test]
+    fn simulate_reports_percentiles_and_criticality() {
+        let pert = sample_pert();
+
+        let summary = pert.simulate(200, &[50.0, 95.0]).unwrap();
+
+        assert_eq!(summary.trials, 200);
+        // Estimates are deterministic, so every trial completes in exactly 4 days.
+        assert_eq!(summary.mean, 4.0);
+        assert_eq!(summary.std_dev, 0.0);
+        assert_eq!(
+            summary.percentiles,
+            vec![(50.0, 4.0), (95.0, 4.0)]
+        );
+
+        let criticality = |name: &str| {
+            summary
+                .criticality
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .index
+        };
+        assert_eq!(criticality("task3"), 1.0);
+        assert_eq!(criticality("task1"), 0.0);
+        assert_eq!(criticality("task2"), 0.0);
+    }
+
+    #[test]
+    fn simulate_samples_pert_beta_when_estimates_differ() {
+        // o=2, m=4, p=12 gives alpha=1.8, beta=4.2 in the scaled Beta distribution, whose
+        // mean (5.0, matching the PERT expected duration) and variance (3.0) differ from
+        // the optimistic==most_likely==pessimistic fixture used elsewhere, so this is the
+        // only test that actually exercises Beta::sample rather than its range<=0 shortcut.
+        let csv = "1,2,2,4,12,1,1.0,task\n";
+        let pert = DataLoader::from_bytes(csv.as_bytes())
+            .unwrap()
+            .to_graph()
+            .unwrap();
+
+        let summary = pert.simulate(20_000, &[]).unwrap();
+
+        assert!((summary.mean - 5.0).abs() < 0.2, "mean = {}", summary.mean);
+        let variance = summary.std_dev.powi(2);
+        assert!((variance - 3.0).abs() < 0.5, "variance = {}", variance);
+        assert!(summary.min >= 2.0);
+        assert!(summary.max <= 12.0);
+    }
+
+    #[test]
+    fn normalizes_multiple_sources_and_sinks() {
+        // Two independent start tasks (1, 2) and two independent end deliverables (3, 4):
+        // start_node/end_node would otherwise reject this for having more than one
+        // zero-in/out-degree event.
+        let csv = "\
+1,3,5,5,5,1,1.0,taskA
+2,4,3,3,3,1,1.0,taskB
+";
+        let pert = DataLoader::from_bytes(csv.as_bytes())
+            .unwrap()
+            .to_graph()
+            .unwrap();
+        let graph = &pert.0;
+
+        assert_eq!(graph.node_count(), 6);
+        assert_eq!(graph.node_weights().filter(|e| e.is_synthetic()).count(), 2);
+        assert_eq!(graph.edge_weights().filter(|t| t.is_synthetic()).count(), 4);
+
+        // Project length is driven by the longer branch (1 -> 3, duration 5), via the
+        // synthetic super-source/super-sink connectors.
+        assert_eq!(
+            graph
+                .node_weights()
+                .filter(|e| e.is_synthetic())
+                .map(|e| e.fastest_begin())
+                .max()
+                .unwrap(),
+            5
+        );
+
+        let critical = pert.critical_paths().unwrap();
+        assert_eq!(critical.len(), 1);
+        assert_eq!(critical[0].duration, 5);
+        assert_eq!(
+            critical[0].tasks,
+            vec![
+                "synthetic-source".to_string(),
+                "taskA".to_string(),
+                "synthetic-sink".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn critical_paths_finds_the_longest_chain() {
+        let pert = sample_pert();
+
+        let paths = pert.critical_paths().unwrap();
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].events, vec![1, 3]);
+        assert_eq!(paths[0].tasks, vec!["task3".to_string()]);
+        assert_eq!(paths[0].duration, 4);
+    }
+
+    #[test]
+    fn crash_shortens_the_critical_path_at_minimum_cost() {
+        let pert = sample_pert();
+
+        let result = pert.crash(3).unwrap();
+
+        assert_eq!(result.achieved_duration, 3);
+        assert_eq!(result.total_cost, 10.0);
+        assert_eq!(result.tasks.len(), 1);
+        assert_eq!(result.tasks[0].name, "task3");
+        assert_eq!(result.tasks[0].days_crashed, 1);
+    }
+
+    #[test]
+    fn crash_errors_when_target_is_infeasible() {
+        // A single task that cannot be crashed at all (duration == crash_duration): the
+        // request below used to hang forever instead of reporting the target infeasible.
+        let csv = "1,2,10,10,10,10,5.0,onlytask\n";
+        let pert = DataLoader::from_bytes(csv.as_bytes())
+            .unwrap()
+            .to_graph()
+            .unwrap();
+
+        assert!(pert.crash(5).is_err());
+    }
+
+    #[test]
+    fn crash_handles_parallel_critical_edges() {
+        // Two parallel critical edges between the same pair of events: if the flow
+        // network keyed them by (from, to) instead of EdgeIndex, one would silently
+        // overwrite the other's capacity.
+        let csv = "\
+1,2,5,5,5,3,10.0,taskA
+1,2,5,5,5,3,5.0,taskB
+2,3,1,1,1,1,1.0,taskC
+";
+        let pert = DataLoader::from_bytes(csv.as_bytes())
+            .unwrap()
+            .to_graph()
+            .unwrap();
+
+        let result = pert.crash(5).unwrap();
+
+        assert_eq!(result.achieved_duration, 5);
+        assert_eq!(result.total_cost, 15.0);
+        let names: HashSet<&str> = result.tasks.iter().map(|t| t.name.as_str()).collect();
+        assert!(names.contains("taskA"));
+        assert!(names.contains("taskB"));
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+
+use petgraph::visit::{EdgeRef, IntoNodeReferences};
+
+use super::layout::Layout;
+use super::pert::PertGraph;
+
+const MARGIN: f64 = 40.0;
+const NODE_RADIUS: f64 = 18.0;
+
+/// Renders a [`PertGraph`] as a left-to-right layered SVG diagram, using [`Layout`] for
+/// positioning instead of shelling out to Graphviz. Critical edges are bold and dummy
+/// (zero-duration) edges are dashed, matching `PertDot`'s styling.
+pub struct PertSvg<'a> {
+    graph: &'a PertGraph,
+    layout: Layout,
+}
+
+impl<'a> PertSvg<'a> {
+    pub fn new(graph: &'a PertGraph) -> Self {
+        let layout = Layout::compute(graph);
+        PertSvg { graph, layout }
+    }
+
+    /// The raw `(event label, x, y)` coordinate table behind the rendered SVG, for callers
+    /// that want to drive their own renderer instead of the bundled one.
+    pub fn coordinates(&self) -> Vec<(u32, f64, f64)> {
+        self.graph
+            .node_references()
+            .map(|(node, event)| {
+                let position = self.layout.position(node);
+                (event.label(), position.x, position.y)
+            })
+            .collect()
+    }
+}
+
+impl<'a> fmt::Display for PertSvg<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.layout.width() + 2.0 * MARGIN;
+        let height = self.layout.height() + 2.0 * MARGIN;
+
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" \
+             viewBox=\"0 0 {:.0} {:.0}\">",
+            width, height, width, height
+        )?;
+        writeln!(
+            f,
+            "  <style>\
+             .task {{ stroke: black; stroke-width: 1; fill: none; }}\
+             .critical {{ stroke-width: 3; }}\
+             .dummy {{ stroke-dasharray: 6,4; }}\
+             .synthetic {{ stroke-dasharray: 2,3; }}\
+             .event {{ fill: white; stroke: black; stroke-width: 1; }}\
+             text {{ font-family: sans-serif; font-size: 12px; }}\
+             </style>"
+        )?;
+
+        for edge in self.graph.edge_references() {
+            let source = self.layout.position(edge.source());
+            let target = self.layout.position(edge.target());
+            let task = edge.weight();
+            let mut classes = vec!["task"];
+            if task.is_synthetic() {
+                classes.push("synthetic");
+            } else if task.is_dummy_path() {
+                classes.push("dummy");
+            } else if task.is_critical_path() {
+                classes.push("critical");
+            }
+            writeln!(
+                f,
+                "  <line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" class=\"{}\"/>",
+                source.x + MARGIN,
+                source.y + MARGIN,
+                target.x + MARGIN,
+                target.y + MARGIN,
+                classes.join(" ")
+            )?;
+        }
+
+        for (node, event) in self.graph.node_references() {
+            let position = self.layout.position(node);
+            let (cx, cy) = (position.x + MARGIN, position.y + MARGIN);
+            let mut classes = vec!["event"];
+            if event.is_synthetic() {
+                classes.push("synthetic");
+            }
+            writeln!(
+                f,
+                "  <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{}\" class=\"{}\"/>",
+                cx,
+                cy,
+                NODE_RADIUS,
+                classes.join(" ")
+            )?;
+            writeln!(
+                f,
+                "  <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\" \
+                 dominant-baseline=\"middle\">{}</text>",
+                cx,
+                cy,
+                event.label()
+            )?;
+        }
+
+        writeln!(f, "</svg>")
+    }
+}